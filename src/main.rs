@@ -1,11 +1,17 @@
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::{Path, PathBuf};
 use std::fs;
 use serde::{Deserialize, Serialize};
+use rayon::prelude::*;
+
+mod search_index;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(short, long)]
     add: Option<String>,
 
@@ -15,13 +21,144 @@ struct Args {
     #[arg(short, long)]
     list: bool,
 
+    /// Register a directory as a root to scan for projects
+    #[arg(long)]
+    root: Option<String>,
+
+    /// Walk every registered root and auto-register the projects found underneath it
+    #[arg(long)]
+    scan: bool,
+
+    /// Maximum directory depth to descend into while scanning
+    #[arg(long, default_value_t = 5)]
+    max_depth: usize,
+
+    /// Also descend into directories whose name starts with '.'
+    #[arg(long)]
+    hidden: bool,
+
+    /// Attach this tag when used with --add, or detach it when used with --remove
+    #[arg(long)]
+    tag: Option<String>,
+
+    /// Restrict --list, jump, and --remove index resolution to projects carrying this tag
+    #[arg(long)]
+    filter: Option<String>,
+
+    /// Store a description for the project passed to --add, searchable via --search
+    #[arg(long)]
+    describe: Option<String>,
+
+    /// Rebuild the full-text search index from all registered projects
+    #[arg(long)]
+    reindex: bool,
+
+    /// Find a project by words in its README or description instead of by name
+    #[arg(long)]
+    search: Option<String>,
+
+    /// Launch an editor at the resolved project instead of printing its path.
+    /// Uses --editor if given, otherwise the configured editor, then
+    /// $EDITOR, then $VISUAL.
+    #[arg(long)]
+    open: bool,
+
+    /// Editor command to use with --open, overriding the configured editor
+    /// and $EDITOR/$VISUAL
+    #[arg(long)]
+    editor: Option<String>,
+
     /// Jump to a project by index or name
     project: Option<String>,
 }
 
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print a shell function that cds into the resolved project in one step
+    Init {
+        /// Shell to generate the function for
+        shell: Shell,
+    },
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+struct ProjectEntry {
+    path: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Number of times this project has been resolved via a jump query
+    #[serde(default)]
+    access_count: u64,
+    /// Unix timestamp of the most recent successful jump resolution
+    #[serde(default)]
+    last_access: u64,
+    /// Freeform text describing the project, indexed alongside its README
+    #[serde(default)]
+    description: Option<String>,
+    /// README mtime as of the last time this project was indexed
+    #[serde(default)]
+    indexed_readme_mtime: Option<u64>,
+    /// Whether this project has ever been added to the search index. Kept
+    /// separate from `indexed_readme_mtime` so a README-less project (whose
+    /// mtime is always `None`) can still be told apart from one that's
+    /// simply never been indexed yet.
+    #[serde(default)]
+    indexed: bool,
+}
+
+impl ProjectEntry {
+    fn new(path: String) -> Self {
+        ProjectEntry {
+            path,
+            tags: Vec::new(),
+            access_count: 0,
+            last_access: 0,
+            description: None,
+            indexed_readme_mtime: None,
+            indexed: false,
+        }
+    }
+}
+
+// Accepts either the legacy plain-string form of an entry or the richer
+// `{ path, tags }` table, so configs written before tags existed keep loading.
+fn deserialize_entries<'de, D>(deserializer: D) -> Result<Vec<ProjectEntry>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Plain(String),
+        Full(ProjectEntry),
+    }
+
+    let entries = Vec::<Repr>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| match entry {
+            Repr::Plain(path) => ProjectEntry::new(path),
+            Repr::Full(entry) => entry,
+        })
+        .collect())
+}
+
 #[derive(Serialize, Deserialize, Default)]
 struct Config {
-    paths: Vec<String>,
+    #[serde(deserialize_with = "deserialize_entries")]
+    paths: Vec<ProjectEntry>,
+    #[serde(default)]
+    roots: Vec<String>,
+    /// Default editor command used by --open when no editor name is given
+    #[serde(default)]
+    editor: Option<String>,
 }
 
 fn get_config_path() -> PathBuf {
@@ -29,6 +166,11 @@ fn get_config_path() -> PathBuf {
     home.join(".teleproj.toml")
 }
 
+fn get_index_dir() -> PathBuf {
+    let home = dirs::home_dir().expect("Could not find home directory");
+    home.join(".teleproj-index")
+}
+
 fn load_config() -> Config {
     let config_path = get_config_path();
     
@@ -65,7 +207,7 @@ fn get_project_name(path: &Path) -> String {
         .to_string()
 }
 
-fn add_path(path_str: &str) {
+fn add_path(path_str: &str, tag: Option<&str>, description: Option<&str>) {
     if !is_valid_path(path_str) {
         eprintln!("Error: The path '{}' does not exist.", path_str);
         std::process::exit(1);
@@ -76,37 +218,266 @@ fn add_path(path_str: &str) {
     let path_string = canonical_path.display().to_string();
 
     let mut config = load_config();
-    
-    // Check if path already exists
-    if config.paths.contains(&path_string) {
-        println!("Path already exists: {}", path_string);
+
+    if let Some(entry) = config.paths.iter_mut().find(|entry| entry.path == path_string) {
+        let mut changed = false;
+        match tag {
+            Some(tag) if !entry.tags.iter().any(|t| t == tag) => {
+                entry.tags.push(tag.to_string());
+                println!("Tagged '{}' with '{}'", path_string, tag);
+                changed = true;
+            }
+            Some(tag) => println!("Path already tagged '{}': {}", tag, path_string),
+            None => println!("Path already exists: {}", path_string),
+        }
+        if let Some(description) = description {
+            entry.description = Some(description.to_string());
+            println!("Updated description for: {}", path_string);
+            changed = true;
+        }
+        if changed {
+            let current_mtime = search_index::readme_mtime(Path::new(&entry.path));
+            match search_index::reindex_one(&get_index_dir(), entry) {
+                Ok(()) => {
+                    entry.indexed_readme_mtime = current_mtime;
+                    entry.indexed = true;
+                }
+                Err(err) => eprintln!("Warning: Failed to update search index: {}", err),
+            }
+            save_config(&config);
+        }
         return;
     }
 
-    config.paths.push(path_string.clone());
+    let mut entry = ProjectEntry::new(path_string.clone());
+    if let Some(tag) = tag {
+        entry.tags.push(tag.to_string());
+    }
+    if let Some(description) = description {
+        entry.description = Some(description.to_string());
+    }
+    let current_mtime = search_index::readme_mtime(Path::new(&entry.path));
+    match search_index::reindex_one(&get_index_dir(), &entry) {
+        Ok(()) => {
+            entry.indexed_readme_mtime = current_mtime;
+            entry.indexed = true;
+        }
+        Err(err) => eprintln!("Warning: Failed to update search index: {}", err),
+    }
+    config.paths.push(entry);
     save_config(&config);
     println!("Added path: {}", path_string);
 }
 
-fn get_paths() -> Vec<PathBuf> {
-    let config = load_config();
-    config.paths
-        .iter()
-        .map(|p| PathBuf::from(p))
-        .filter(|path| path.exists()) 
+fn is_git_repo(dir: &Path) -> bool {
+    dir.join(".git").exists()
+}
+
+fn add_root(path_str: &str) {
+    if !is_valid_path(path_str) {
+        eprintln!("Error: The path '{}' does not exist.", path_str);
+        std::process::exit(1);
+    }
+
+    let canonical_path = fs::canonicalize(path_str)
+        .expect("Failed to canonicalize path");
+    let path_string = canonical_path.display().to_string();
+
+    let mut config = load_config();
+
+    if config.roots.contains(&path_string) {
+        println!("Root already exists: {}", path_string);
+        return;
+    }
+
+    config.roots.push(path_string.clone());
+    save_config(&config);
+    println!("Added root: {}", path_string);
+}
+
+// Recursively discovers projects under `dir`. Each directory's `read_dir`
+// entries are fanned out over the thread pool via `par_bridge()`, and
+// descent stops as soon as a directory containing `.git` is found, so
+// nested submodules of an already-discovered project aren't registered
+// individually.
+fn scan_root(dir: &Path, max_depth: usize, include_hidden: bool) -> Vec<String> {
+    if is_git_repo(dir) {
+        return vec![dir.display().to_string()];
+    }
+
+    if max_depth == 0 {
+        return Vec::new();
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    entries
+        .par_bridge()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter(|entry| include_hidden || !entry.file_name().to_string_lossy().starts_with('.'))
+        .flat_map(|entry| scan_root(&entry.path(), max_depth - 1, include_hidden))
         .collect()
 }
 
-fn remove_path_by_index(index: usize) {
+fn run_scan(max_depth: usize, include_hidden: bool) {
     let mut config = load_config();
-    
-    if index < config.paths.len() {
-        let removed = config.paths.remove(index);
+
+    if config.roots.is_empty() {
+        println!("No roots registered yet. Use --root to add one!");
+        return;
+    }
+
+    let mut discovered: Vec<String> = config
+        .roots
+        .par_iter()
+        .flat_map(|root| scan_root(Path::new(root), max_depth, include_hidden))
+        .collect();
+    discovered.sort();
+    discovered.dedup();
+
+    let mut added = 0;
+    for path in discovered {
+        if !config.paths.iter().any(|entry| entry.path == path) {
+            let mut entry = ProjectEntry::new(path);
+            let current_mtime = search_index::readme_mtime(Path::new(&entry.path));
+            match search_index::reindex_one(&get_index_dir(), &entry) {
+                Ok(()) => {
+                    entry.indexed_readme_mtime = current_mtime;
+                    entry.indexed = true;
+                }
+                Err(err) => eprintln!("Warning: Failed to index '{}': {}", entry.path, err),
+            }
+            config.paths.push(entry);
+            added += 1;
+        }
+    }
+
+    save_config(&config);
+    println!("Scan complete: {} new project(s) registered.", added);
+}
+
+// Applies the tag filter and existence check shared by --list, jump, and
+// --remove index resolution.
+fn filter_entries(paths: Vec<ProjectEntry>, filter: Option<&str>) -> Vec<ProjectEntry> {
+    paths
+        .into_iter()
+        .filter(|entry| filter.is_none_or(|tag| entry.tags.iter().any(|t| t == tag)))
+        .filter(|entry| Path::new(&entry.path).exists())
+        .collect()
+}
+
+fn get_entries(filter: Option<&str>) -> Vec<ProjectEntry> {
+    filter_entries(load_config().paths, filter)
+}
+
+fn get_paths(filter: Option<&str>) -> Vec<PathBuf> {
+    get_entries(filter)
+        .into_iter()
+        .map(|entry| PathBuf::from(entry.path))
+        .collect()
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// Records a successful jump resolution so frecency can favor it next time.
+fn record_access(path: &Path) {
+    let mut config = load_config();
+    let path_string = path.display().to_string();
+    if let Some(entry) = config.paths.iter_mut().find(|entry| entry.path == path_string) {
+        entry.access_count += 1;
+        entry.last_access = now_secs();
         save_config(&config);
-        println!("Removed path: {}", removed);
+    }
+}
+
+// Bucketed decay multiplier: recently-visited projects are weighted much more
+// heavily than ones not touched in a while.
+fn decay(elapsed_secs: u64) -> f64 {
+    const HOUR: u64 = 3600;
+    const DAY: u64 = 24 * HOUR;
+    const WEEK: u64 = 7 * DAY;
+
+    if elapsed_secs <= HOUR {
+        4.0
+    } else if elapsed_secs <= DAY {
+        2.0
+    } else if elapsed_secs <= WEEK {
+        1.0
     } else {
-        eprintln!("Error: Index {} is out of range", index);
-        std::process::exit(1);
+        0.5
+    }
+}
+
+fn frecency_score(entry: &ProjectEntry, now: u64) -> f64 {
+    if entry.access_count == 0 {
+        return 0.0;
+    }
+    entry.access_count as f64 * decay(now.saturating_sub(entry.last_access))
+}
+
+// Combines the string-similarity match score with frecency so frequently- and
+// recently-visited projects float to the top even when the match is fuzzier.
+fn combined_score(match_score: i32, entry: &ProjectEntry, now: u64) -> f64 {
+    match_score as f64 + frecency_score(entry, now)
+}
+
+// `index` is resolved the same way --list/jump resolve it: against the
+// tag-and-existence-filtered view from `get_entries`, not the raw config
+// order. Otherwise an index shown under --filter could remove a different,
+// unfiltered project entirely. Returns the message to print on success, or
+// an error message to print and exit on.
+fn remove_entry_by_index(
+    config: &mut Config,
+    index: usize,
+    tag: Option<&str>,
+    filter: Option<&str>,
+) -> Result<String, String> {
+    let Some(target_path) = filter_entries(config.paths.clone(), filter)
+        .get(index)
+        .map(|entry| entry.path.clone())
+    else {
+        return Err(format!("Index {} is out of range", index));
+    };
+
+    let Some(config_index) = config.paths.iter().position(|entry| entry.path == target_path) else {
+        return Err(format!("Index {} is out of range", index));
+    };
+
+    if let Some(tag) = tag {
+        let entry = &mut config.paths[config_index];
+        return match entry.tags.iter().position(|t| t == tag) {
+            Some(pos) => {
+                entry.tags.remove(pos);
+                Ok(format!("Removed tag '{}' from: {}", tag, entry.path))
+            }
+            None => Err(format!("Path at index {} is not tagged '{}'", index, tag)),
+        };
+    }
+
+    let removed = config.paths.remove(config_index);
+    Ok(format!("Removed path: {}", removed.path))
+}
+
+fn remove_path_by_index(index: usize, tag: Option<&str>, filter: Option<&str>) {
+    let mut config = load_config();
+    match remove_entry_by_index(&mut config, index, tag, filter) {
+        Ok(message) => {
+            save_config(&config);
+            println!("{}", message);
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            std::process::exit(1);
+        }
     }
 }
 
@@ -131,11 +502,11 @@ fn calculate_match_score(project_name: &str, query: &str) -> i32 {
     let mut current_char = query_chars.next();
     
     for c in project_lower.chars() {
-        if let Some(query_char) = current_char {
-            if c == query_char {
-                score += 1;
-                current_char = query_chars.next();
-            }
+        if let Some(query_char) = current_char
+            && c == query_char
+        {
+            score += 1;
+            current_char = query_chars.next();
         }
     }
     
@@ -146,44 +517,108 @@ fn calculate_match_score(project_name: &str, query: &str) -> i32 {
     }
 }
 
-fn find_and_print_path(query: &str) {
-    let paths = get_paths();
-    
-    if let Ok(index) = query.parse::<usize>() {
-        if let Some(path) = paths.get(index) {
-            println!("{}", path.display());
-            return;
+// Resolves the editor to launch: an explicit --editor argument wins, then the
+// configured editor, then $EDITOR, then $VISUAL.
+fn resolve_editor(explicit: Option<&str>, configured: Option<&str>) -> Option<String> {
+    explicit
+        .map(String::from)
+        .or_else(|| configured.map(String::from))
+        .or_else(|| std::env::var("EDITOR").ok())
+        .or_else(|| std::env::var("VISUAL").ok())
+}
+
+// Splits a resolved editor string like "code --wait" into its program and
+// arguments, and builds the Command to launch it at `path`. `$EDITOR`/
+// `$VISUAL` and the `editor` config field regularly carry flags, so the
+// whole string can't be passed to `Command::new` as the program name.
+fn build_editor_command(editor: &str, path: &Path) -> Option<std::process::Command> {
+    let mut parts = editor.split_whitespace();
+    let program = parts.next()?;
+    let mut command = std::process::Command::new(program);
+    command.args(parts).arg(path);
+    Some(command)
+}
+
+fn open_in_editor(path: &Path, editor_override: Option<&str>) {
+    let config = load_config();
+    let editor = resolve_editor(editor_override, config.editor.as_deref());
+
+    let Some(editor) = editor else {
+        eprintln!("Error: No editor configured. Set one in the config, or $EDITOR/$VISUAL.");
+        std::process::exit(1);
+    };
+
+    let Some(mut command) = build_editor_command(&editor, path) else {
+        eprintln!("Error: Configured editor is empty.");
+        std::process::exit(1);
+    };
+
+    // Most $EDITOR/$VISUAL values are terminal programs (vim, nano, emacs)
+    // that need to own the controlling terminal, so block until they exit
+    // rather than racing the next shell prompt against a detached child.
+    match command.status() {
+        Ok(status) if !status.success() => std::process::exit(status.code().unwrap_or(1)),
+        Ok(_) => {}
+        Err(err) => {
+            eprintln!("Error: Failed to launch '{}': {}", editor, err);
+            std::process::exit(1);
         }
     }
-    
-    let mut matches: Vec<(usize, &PathBuf, i32)> = paths
+}
+
+// Either prints the resolved path (default, shell-integration-friendly) or
+// launches an editor at it when --open was passed.
+fn emit_path(path: &Path, open: bool, editor_override: Option<&str>) {
+    if open {
+        open_in_editor(path, editor_override);
+    } else {
+        println!("{}", path.display());
+    }
+}
+
+fn find_and_print_path(query: &str, filter: Option<&str>, open: bool, editor_override: Option<&str>) {
+    let entries = get_entries(filter);
+
+    if let Ok(index) = query.parse::<usize>()
+        && let Some(entry) = entries.get(index)
+    {
+        let path = PathBuf::from(&entry.path);
+        record_access(&path);
+        emit_path(&path, open, editor_override);
+        return;
+    }
+
+    let now = now_secs();
+    let mut matches: Vec<(usize, &ProjectEntry, f64)> = entries
         .iter()
         .enumerate()
-        .filter_map(|(i, path)| {
-            let project_name = get_project_name(path);
+        .filter_map(|(i, entry)| {
+            let project_name = get_project_name(Path::new(&entry.path));
             let score = calculate_match_score(&project_name, query);
             if score > 0 {
-                Some((i, path, score))
+                Some((i, entry, combined_score(score, entry, now)))
             } else {
                 None
             }
         })
         .collect();
-    
-    matches.sort_by(|a, b| b.2.cmp(&a.2));
-    
+
+    matches.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+
     match matches.len() {
         0 => {
             eprintln!("Error: No project found matching '{}'", query);
             std::process::exit(1);
         }
         1 => {
-            println!("{}", matches[0].1.display());
+            let path = PathBuf::from(&matches[0].1.path);
+            record_access(&path);
+            emit_path(&path, open, editor_override);
         }
         _ => {
             eprintln!("Multiple projects match '{}'. Please choose:", query);
-            for (i, (original_index, path, _score)) in matches.iter().take(5).enumerate() {
-                let project_name = get_project_name(path);
+            for (i, (original_index, entry, _score)) in matches.iter().take(5).enumerate() {
+                let project_name = get_project_name(Path::new(&entry.path));
                 eprintln!("  {}: {} (index {})", i, project_name, original_index);
             }
             eprintln!("\nUse the specific index number to jump to a project.");
@@ -192,17 +627,129 @@ fn find_and_print_path(query: &str) {
     }
 }
 
+// Emits a shell function that wraps `teleproj`, cding into the resolved
+// project on success. The candidate list on a multiple-match error is already
+// written to stderr by `find_and_print_path`, so only stdout needs capturing,
+// and a non-zero exit from the capture must abort the `cd`.
+fn init_script(shell: &Shell) -> &'static str {
+    match shell {
+        Shell::Bash | Shell::Zsh => {
+            "tp() {\n    local dest\n    dest=\"$(teleproj \"$1\")\" || return\n    cd \"$dest\" || return\n}"
+        }
+        Shell::Fish => {
+            "function tp\n    set -l dest (teleproj $argv[1])\n    or return\n    cd $dest\nend"
+        }
+    }
+}
+
+fn print_init_script(shell: &Shell) {
+    println!("{}", init_script(shell));
+}
+
+// Re-indexes any registered project whose README has changed since it was
+// last indexed, so `--search` stays fresh without requiring an explicit
+// `--reindex`.
+fn sync_stale_entries(config: &mut Config) {
+    let index_dir = get_index_dir();
+    let mut changed = false;
+
+    for entry in config.paths.iter_mut() {
+        let current_mtime = search_index::readme_mtime(Path::new(&entry.path));
+        if entry.indexed && current_mtime == entry.indexed_readme_mtime {
+            continue;
+        }
+
+        match search_index::reindex_one(&index_dir, entry) {
+            Ok(()) => {
+                entry.indexed_readme_mtime = current_mtime;
+                entry.indexed = true;
+                changed = true;
+            }
+            Err(err) => eprintln!("Warning: Failed to index '{}': {}", entry.path, err),
+        }
+    }
+
+    if changed {
+        save_config(config);
+    }
+}
+
+fn run_reindex() {
+    let mut config = load_config();
+
+    match search_index::reindex_all(&get_index_dir(), &config.paths) {
+        Ok(count) => {
+            for entry in config.paths.iter_mut() {
+                entry.indexed_readme_mtime = search_index::readme_mtime(Path::new(&entry.path));
+                entry.indexed = true;
+            }
+            save_config(&config);
+            println!("Reindexed {} project(s).", count);
+        }
+        Err(err) => {
+            eprintln!("Error: Failed to build search index: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run_search(query: &str) {
+    let mut config = load_config();
+    sync_stale_entries(&mut config);
+
+    match search_index::search(&get_index_dir(), query, 10) {
+        Ok(paths) if paths.is_empty() => {
+            eprintln!("Error: No project found matching '{}'", query);
+            std::process::exit(1);
+        }
+        Ok(paths) => {
+            for path in paths {
+                println!("{}", path);
+            }
+        }
+        Err(err) => {
+            eprintln!("Error: Search failed: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
     let args = Args::parse();
 
+    if let Some(Command::Init { shell }) = args.command.as_ref() {
+        print_init_script(shell);
+        return;
+    }
+
     if let Some(add_path_str) = args.add.as_ref() {
-        add_path(add_path_str.as_str());
+        add_path(add_path_str.as_str(), args.tag.as_deref(), args.describe.as_deref());
+        return;
+    }
+
+    if let Some(root_str) = args.root.as_ref() {
+        add_root(root_str.as_str());
+        return;
+    }
+
+    if args.scan {
+        run_scan(args.max_depth, args.hidden);
+        return;
+    }
+
+    if args.reindex {
+        run_reindex();
+        return;
+    }
+
+    if let Some(query) = args.search.as_ref() {
+        run_search(query);
         return;
     }
 
     if let Some(remove_index) = args.remove.as_ref() {
         match remove_index.parse::<usize>() {
-            Ok(index) => remove_path_by_index(index),
+            Ok(index) => remove_path_by_index(index, args.tag.as_deref(), args.filter.as_deref()),
             Err(_) => {
                 eprintln!("Error: Remove argument must be a valid number");
                 std::process::exit(1);
@@ -212,7 +759,7 @@ fn main() {
     }
 
     if args.list {
-        let paths = get_paths();
+        let paths = get_paths(args.filter.as_deref());
         if paths.is_empty() {
             println!("No paths saved yet. Use --add to add some!");
         } else {
@@ -226,9 +773,163 @@ fn main() {
     }
 
     if let Some(project) = args.project.as_ref() {
-        find_and_print_path(project);
+        find_and_print_path(project, args.filter.as_deref(), args.open, args.editor.as_deref());
         return;
     }
 
     println!("Use --help for usage information");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    #[test]
+    fn resolve_editor_prefers_explicit_then_configured() {
+        assert_eq!(
+            resolve_editor(Some("explicit"), Some("configured")),
+            Some("explicit".to_string())
+        );
+        assert_eq!(
+            resolve_editor(None, Some("configured")),
+            Some("configured".to_string())
+        );
+    }
+
+    #[test]
+    fn resolve_editor_falls_back_to_editor_then_visual_env() {
+        // SAFETY: no other test reads or writes $EDITOR/$VISUAL.
+        unsafe {
+            std::env::remove_var("EDITOR");
+            std::env::remove_var("VISUAL");
+        }
+        assert_eq!(resolve_editor(None, None), None);
+
+        unsafe {
+            std::env::set_var("VISUAL", "visual-editor");
+        }
+        assert_eq!(resolve_editor(None, None), Some("visual-editor".to_string()));
+
+        unsafe {
+            std::env::set_var("EDITOR", "editor-editor");
+        }
+        assert_eq!(resolve_editor(None, None), Some("editor-editor".to_string()));
+
+        unsafe {
+            std::env::remove_var("EDITOR");
+            std::env::remove_var("VISUAL");
+        }
+    }
+
+    #[test]
+    fn build_editor_command_splits_program_from_args() {
+        let command = build_editor_command("code --wait", Path::new("/tmp/proj")).unwrap();
+
+        assert_eq!(command.get_program(), "code");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec![OsStr::new("--wait"), OsStr::new("/tmp/proj")]
+        );
+    }
+
+    #[test]
+    fn build_editor_command_rejects_a_blank_editor() {
+        assert!(build_editor_command("   ", Path::new("/tmp/proj")).is_none());
+    }
+
+    #[test]
+    fn init_script_aborts_the_cd_when_teleproj_fails() {
+        assert!(init_script(&Shell::Bash).contains("dest=\"$(teleproj \"$1\")\" || return"));
+        assert!(init_script(&Shell::Bash).contains("cd \"$dest\" || return"));
+        assert!(init_script(&Shell::Zsh).contains("|| return"));
+        assert!(init_script(&Shell::Fish).contains("or return"));
+    }
+
+    #[test]
+    fn decay_buckets_by_elapsed_time() {
+        assert_eq!(decay(0), 4.0);
+        assert_eq!(decay(3600), 4.0);
+        assert_eq!(decay(3601), 2.0);
+        assert_eq!(decay(24 * 3600), 2.0);
+        assert_eq!(decay(24 * 3600 + 1), 1.0);
+        assert_eq!(decay(7 * 24 * 3600), 1.0);
+        assert_eq!(decay(7 * 24 * 3600 + 1), 0.5);
+    }
+
+    #[test]
+    fn frecency_score_is_zero_without_access_history() {
+        let entry = ProjectEntry::new("/tmp/unused".to_string());
+        assert_eq!(frecency_score(&entry, 1_000_000), 0.0);
+    }
+
+    #[test]
+    fn remove_index_resolves_against_filtered_view_when_orderings_diverge() {
+        let untagged_dir = tempfile::tempdir().unwrap();
+        let tagged_dir = tempfile::tempdir().unwrap();
+
+        let untagged = ProjectEntry::new(untagged_dir.path().display().to_string());
+        let mut tagged = ProjectEntry::new(tagged_dir.path().display().to_string());
+        tagged.tags.push("work".to_string());
+
+        // Raw config order puts the untagged entry first, but filtering by
+        // "work" puts the tagged entry at index 0 instead.
+        let mut config = Config {
+            paths: vec![untagged.clone(), tagged.clone()],
+            ..Default::default()
+        };
+
+        // Index 0 under --filter work must resolve to the tagged entry at
+        // config index 1, not the untagged entry that's actually first.
+        let message = remove_entry_by_index(&mut config, 0, None, Some("work")).unwrap();
+
+        assert_eq!(message, format!("Removed path: {}", tagged.path));
+        assert_eq!(config.paths, vec![untagged]);
+    }
+
+    #[test]
+    fn deserialize_entries_accepts_legacy_plain_string_paths() {
+        let config: Config = toml::from_str(r#"paths = ["/a", "/b"]"#).unwrap();
+
+        assert_eq!(config.paths.len(), 2);
+        assert_eq!(config.paths[0].path, "/a");
+        assert_eq!(config.paths[1].path, "/b");
+    }
+
+    #[test]
+    fn combined_score_adds_frecency_to_the_match_score() {
+        let mut entry = ProjectEntry::new("/tmp/used".to_string());
+        entry.access_count = 3;
+        entry.last_access = 1_000_000;
+        let now = 1_000_000 + 60;
+
+        assert_eq!(combined_score(100, &entry, now), 100.0 + 3.0 * decay(60));
+    }
+
+    #[test]
+    fn scan_root_stops_descending_at_a_git_repo() {
+        let root = tempfile::tempdir().unwrap();
+
+        let project = root.path().join("project");
+        fs::create_dir_all(project.join(".git")).unwrap();
+        fs::create_dir_all(project.join("vendor").join("submodule").join(".git")).unwrap();
+
+        let found = scan_root(root.path(), 5, false);
+
+        assert_eq!(found, vec![project.display().to_string()]);
+    }
+
+    #[test]
+    fn scan_root_skips_hidden_dirs_unless_included() {
+        let root = tempfile::tempdir().unwrap();
+
+        let hidden_project = root.path().join(".hidden").join("project");
+        fs::create_dir_all(hidden_project.join(".git")).unwrap();
+
+        assert!(scan_root(root.path(), 5, false).is_empty());
+        assert_eq!(
+            scan_root(root.path(), 5, true),
+            vec![hidden_project.display().to_string()]
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::QueryParser;
+use tantivy::schema::{Field, Schema, Value, STORED, STRING, TEXT};
+use tantivy::{doc, Index, IndexWriter, TantivyDocument, Term};
+
+use crate::ProjectEntry;
+
+const README_CANDIDATES: &[&str] = &["README.md", "README", "README.txt", "Readme.md"];
+
+struct Fields {
+    path: Field,
+    name: Field,
+    body: Field,
+}
+
+fn schema() -> (Schema, Fields) {
+    let mut builder = Schema::builder();
+    // Indexed as STRING (not just STORED) so `reindex_one` can look it up by
+    // exact value via `delete_term` to drop the prior document for a project.
+    let path = builder.add_text_field("path", STRING | STORED);
+    let name = builder.add_text_field("name", TEXT | STORED);
+    let body = builder.add_text_field("body", TEXT);
+    (builder.build(), Fields { path, name, body })
+}
+
+fn open_index(index_dir: &Path) -> tantivy::Result<(Index, Fields)> {
+    fs::create_dir_all(index_dir)?;
+    let (schema, fields) = schema();
+    let dir = MmapDirectory::open(index_dir)?;
+    let index = Index::open_or_create(dir, schema)?;
+    Ok((index, fields))
+}
+
+/// Locates a project's README under one of the usual names, if present.
+pub fn readme_path(project_dir: &Path) -> Option<PathBuf> {
+    README_CANDIDATES
+        .iter()
+        .map(|name| project_dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Unix timestamp of the project's README modification time, used to detect
+/// when a project needs re-indexing.
+pub fn readme_mtime(project_dir: &Path) -> Option<u64> {
+    let path = readme_path(project_dir)?;
+    let modified = fs::metadata(&path).ok()?.modified().ok()?;
+    modified.duration_since(std::time::UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+fn project_name(entry: &ProjectEntry) -> String {
+    Path::new(&entry.path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+fn project_body(entry: &ProjectEntry) -> String {
+    let mut body = entry.description.clone().unwrap_or_default();
+    if let Some(readme) = readme_path(Path::new(&entry.path))
+        && let Ok(text) = fs::read_to_string(&readme)
+    {
+        body.push('\n');
+        body.push_str(&text);
+    }
+    body
+}
+
+fn entry_document(fields: &Fields, entry: &ProjectEntry) -> TantivyDocument {
+    doc!(
+        fields.path => entry.path.clone(),
+        fields.name => project_name(entry),
+        fields.body => project_body(entry),
+    )
+}
+
+/// Rebuilds the whole index from scratch, e.g. for `--reindex`.
+pub fn reindex_all(index_dir: &Path, entries: &[ProjectEntry]) -> tantivy::Result<usize> {
+    let (index, fields) = open_index(index_dir)?;
+    let mut writer: IndexWriter = index.writer(50_000_000)?;
+    writer.delete_all_documents()?;
+    for entry in entries {
+        writer.add_document(entry_document(&fields, entry))?;
+    }
+    writer.commit()?;
+    Ok(entries.len())
+}
+
+/// Re-indexes a single project, e.g. because it was just added or its README changed.
+pub fn reindex_one(index_dir: &Path, entry: &ProjectEntry) -> tantivy::Result<()> {
+    let (index, fields) = open_index(index_dir)?;
+    let mut writer: IndexWriter = index.writer(50_000_000)?;
+    writer.delete_term(Term::from_field_text(fields.path, &entry.path));
+    writer.add_document(entry_document(&fields, entry))?;
+    writer.commit()?;
+    Ok(())
+}
+
+/// Queries the index, returning matching project paths ranked by relevance.
+pub fn search(index_dir: &Path, query_str: &str, limit: usize) -> tantivy::Result<Vec<String>> {
+    let (index, fields) = open_index(index_dir)?;
+    let reader = index.reader()?;
+    let searcher = reader.searcher();
+    let parser = QueryParser::for_index(&index, vec![fields.name, fields.body]);
+    let query = parser.parse_query(query_str)?;
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit).order_by_score())?;
+
+    let mut results = Vec::new();
+    for (_score, doc_address) in top_docs {
+        let retrieved: TantivyDocument = searcher.doc(doc_address)?;
+        if let Some(path) = retrieved.get_first(fields.path).and_then(|v| v.as_str()) {
+            results.push(path.to_string());
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProjectEntry;
+
+    #[test]
+    fn reindex_one_replaces_rather_than_duplicates() {
+        let project_dir = tempfile::tempdir().unwrap();
+        fs::write(project_dir.path().join("README.md"), "alpha bravo charlie").unwrap();
+        let entry = ProjectEntry {
+            path: project_dir.path().display().to_string(),
+            ..Default::default()
+        };
+
+        let index_dir = tempfile::tempdir().unwrap();
+        reindex_one(index_dir.path(), &entry).unwrap();
+        reindex_one(index_dir.path(), &entry).unwrap();
+
+        let results = search(index_dir.path(), "alpha", 10).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+}